@@ -1,10 +1,12 @@
 //! Show toggle controls using togglers.
+use std::cell::Cell;
 use std::hash::Hash;
+use std::time::{Duration, Instant};
 
 use crate::{
-    event, layout, mouse, row, text, Align, Clipboard, Element, Event, Hasher,
-    HorizontalAlignment, Layout, Length, Point, Rectangle, Row, Text,
-    VerticalAlignment, Widget,
+    accessibility, event, keyboard, layout, mouse, row, text, widget, Align,
+    Clipboard, Element, Event, Hasher, HorizontalAlignment, Layout, Length,
+    Point, Rectangle, Row, Text, VerticalAlignment, Widget,
 };
 
 /// A toggler widget
@@ -12,19 +14,21 @@ use crate::{
 /// # Example
 ///
 /// ```
-/// # type Toggler<Message> = iced_native::Toggler<Message, iced_native::renderer::Null>;
+/// # type Toggler<'a, Message> = iced_native::Toggler<'a, Message, iced_native::renderer::Null>;
 /// #
 /// pub enum Message {
 ///     TogglerToggled(bool),
 /// }
 ///
+/// let mut state = iced_native::toggler::State::new();
 /// let is_active = true;
 ///
-/// Toggler::new(is_active, "Toggle me!", |b| Message::TogglerToggled(b));
+/// Toggler::new(&mut state, is_active, "Toggle me!", |b| Message::TogglerToggled(b));
 /// ```
 ///
 #[allow(missing_debug_implementations)]
-pub struct Toggler<Message, Renderer: self::Renderer + text::Renderer> {
+pub struct Toggler<'a, Message, Renderer: self::Renderer + text::Renderer> {
+    state: &'a mut State,
     is_active: bool,
     on_toggle: Box<dyn Fn(bool) -> Message>,
     label: String,
@@ -35,14 +39,21 @@ pub struct Toggler<Message, Renderer: self::Renderer + text::Renderer> {
     spacing: u16,
     font: Renderer::Font,
     style: Renderer::Style,
+    on_right_press: Option<Box<dyn Fn(Event) -> Message>>,
+    on_mouse_enter: Option<Box<dyn Fn(Event) -> Message>>,
+    on_mouse_exit: Option<Box<dyn Fn(Event) -> Message>>,
+    animation_duration: Duration,
+    label_position: LabelPosition,
 }
 
-impl<Message, Renderer: self::Renderer + text::Renderer>
-    Toggler<Message, Renderer>
+impl<'a, Message, Renderer: self::Renderer + text::Renderer>
+    Toggler<'a, Message, Renderer>
 {
     /// Creates a new [`Toggler`].
     ///
     /// It expects:
+    ///   * the local [`State`] of the [`Toggler`], which keeps track of
+    ///     things like keyboard focus
     ///   * a boolean describing whether the [`Toggler`] is checked or not
     ///   * the label of the [`Toggler`]
     ///   * a function that will be called when the [`Toggler`] is toggled. It
@@ -50,11 +61,18 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
     ///     `Message`.
     ///
     /// [`Toggler`]: struct.Toggler.html
-    pub fn new<F>(is_active: bool, label: impl Into<String>, f: F) -> Self
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        is_active: bool,
+        label: impl Into<String>,
+        f: F,
+    ) -> Self
     where
         F: 'static + Fn(bool) -> Message,
     {
         Toggler {
+            state,
             is_active,
             on_toggle: Box::new(f),
             label: label.into(),
@@ -65,6 +83,11 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
             spacing: 0,
             font: Renderer::Font::default(),
             style: Renderer::Style::default(),
+            on_right_press: None,
+            on_mouse_enter: None,
+            on_mouse_exit: None,
+            animation_duration: Duration::from_millis(150),
+            label_position: LabelPosition::Left,
         }
     }
 
@@ -124,14 +147,75 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
         self.style = style.into();
         self
     }
+
+    /// Sets the message that should be produced when the right mouse
+    /// button is pressed over the [`Toggler`].
+    ///
+    /// This can be used to attach a context menu to the [`Toggler`]
+    /// without wrapping it in a separate mouse area.
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn on_right_press(
+        mut self,
+        f: impl Fn(Event) -> Message + 'static,
+    ) -> Self {
+        self.on_right_press = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when the mouse cursor
+    /// enters the bounds of the [`Toggler`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn on_mouse_enter(
+        mut self,
+        f: impl Fn(Event) -> Message + 'static,
+    ) -> Self {
+        self.on_mouse_enter = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when the mouse cursor
+    /// exits the bounds of the [`Toggler`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn on_mouse_exit(
+        mut self,
+        f: impl Fn(Event) -> Message + 'static,
+    ) -> Self {
+        self.on_mouse_exit = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the duration of the thumb's slide animation when the
+    /// [`Toggler`] switches between off and on.
+    ///
+    /// A zero duration disables the animation, snapping the thumb to its
+    /// target position instead.
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn animation_duration(mut self, duration: Duration) -> Self {
+        self.animation_duration = duration;
+        self
+    }
+
+    /// Sets the [`LabelPosition`] of the [`Toggler`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    /// [`LabelPosition`]: enum.LabelPosition.html
+    pub fn label_position(mut self, position: LabelPosition) -> Self {
+        self.label_position = position;
+        self
+    }
 }
 
-impl<Message, Renderer> Widget<Message, Renderer> for Toggler<Message, Renderer>
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Toggler<'a, Message, Renderer>
 where
     Renderer: self::Renderer + text::Renderer + row::Renderer,
 {
     fn width(&self) -> Length {
-        self.width
+        self.effective_width()
     }
 
     fn height(&self) -> Length {
@@ -143,25 +227,26 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        Row::<(), Renderer>::new()
-            .width(self.width)
+        let toggle_box = Row::new()
+            .width(Length::Units(2 * self.size))
+            .height(Length::Units(self.size));
+
+        let row = Row::<(), Renderer>::new()
+            .width(self.effective_width())
             .spacing(self.spacing)
-            .align_items(Align::Center)
-            .push(
-                Text::new(&self.label)
-                    .horizontal_alignment(
-                        self.text_align.unwrap_or(HorizontalAlignment::Left),
-                    )
-                    .font(self.font)
-                    .width(self.width)
-                    .size(self.text_size.unwrap_or(renderer.default_size())),
-            )
-            .push(
-                Row::new()
-                    .width(Length::Units(2 * self.size))
-                    .height(Length::Units(self.size)),
-            )
-            .layout(renderer, limits)
+            .align_items(Align::Center);
+
+        let row = match self.label_position {
+            LabelPosition::Left => {
+                row.push(self.label_widget(renderer)).push(toggle_box)
+            }
+            LabelPosition::Right => {
+                row.push(toggle_box).push(self.label_widget(renderer))
+            }
+            LabelPosition::None => row.push(toggle_box),
+        };
+
+        row.layout(renderer, limits)
     }
 
     fn on_event(
@@ -178,13 +263,67 @@ where
                 let mouse_over = layout.bounds().contains(cursor_position);
 
                 if mouse_over {
+                    self.state.focus();
                     messages.push((self.on_toggle)(!self.is_active));
 
                     event::Status::Captured
                 } else {
+                    self.state.unfocus();
+
                     event::Status::Ignored
                 }
             }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
+                let mouse_over = layout.bounds().contains(cursor_position);
+
+                if mouse_over {
+                    if let Some(on_right_press) = &self.on_right_press {
+                        messages.push(on_right_press(event));
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let is_mouse_over = layout.bounds().contains(cursor_position);
+                let was_hovered = self.state.is_hovered();
+                self.state.set_hovered(is_mouse_over);
+
+                if is_mouse_over && !was_hovered {
+                    if let Some(on_mouse_enter) = &self.on_mouse_enter {
+                        messages.push(on_mouse_enter(event));
+
+                        return event::Status::Captured;
+                    }
+                } else if !is_mouse_over && was_hovered {
+                    if let Some(on_mouse_exit) = &self.on_mouse_exit {
+                        messages.push(on_mouse_exit(event));
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                event::Status::Ignored
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if self.state.is_focused() => match key_code {
+                keyboard::KeyCode::Space | keyboard::KeyCode::Enter => {
+                    messages.push((self.on_toggle)(!self.is_active));
+
+                    event::Status::Captured
+                }
+                _ => event::Status::Ignored,
+            },
+            Event::Accessibility(accessibility::Action::Activate(id))
+                if id == self.accessibility_id() =>
+            {
+                messages.push((self.on_toggle)(!self.is_active));
+
+                event::Status::Captured
+            }
             _ => event::Status::Ignored,
         }
     }
@@ -200,29 +339,43 @@ where
         let bounds = layout.bounds();
         let mut children = layout.children();
 
-        let label_layout = children.next().unwrap();
-        let toggler_layout = children.next().unwrap();
+        let (label_layout, toggler_layout) = match self.label_position {
+            LabelPosition::Left => {
+                (Some(children.next().unwrap()), children.next().unwrap())
+            }
+            LabelPosition::Right => {
+                let toggler_layout = children.next().unwrap();
+                (Some(children.next().unwrap()), toggler_layout)
+            }
+            LabelPosition::None => (None, children.next().unwrap()),
+        };
         let toggler_bounds = toggler_layout.bounds();
 
-        let label = text::Renderer::draw(
-            renderer,
-            defaults,
-            label_layout.bounds(),
-            &self.label,
-            self.text_size.unwrap_or(renderer.default_size()),
-            self.font,
-            None,
-            self.text_align.unwrap_or(HorizontalAlignment::Left),
-            VerticalAlignment::Center,
-        );
+        let label = label_layout.map(|label_layout| {
+            text::Renderer::draw(
+                renderer,
+                defaults,
+                label_layout.bounds(),
+                &self.label,
+                self.text_size.unwrap_or(renderer.default_size()),
+                self.font,
+                None,
+                self.text_align.unwrap_or(HorizontalAlignment::Left),
+                VerticalAlignment::Center,
+            )
+        });
 
         let is_mouse_over = bounds.contains(cursor_position);
+        let progress =
+            self.state.progress(self.is_active, self.animation_duration);
 
         self::Renderer::draw(
             renderer,
             toggler_bounds,
             self.is_active,
             is_mouse_over,
+            self.state.is_focused(),
+            progress,
             label,
             &self.style,
         )
@@ -232,7 +385,288 @@ where
         struct Marker;
         std::any::TypeId::of::<Marker>().hash(state);
 
-        self.label.hash(state)
+        self.label.hash(state);
+        self.label_position.hash(state);
+    }
+
+    fn accessibility(&self, layout: Layout<'_>) -> Option<accessibility::Node> {
+        Some(accessibility::Node {
+            id: self.accessibility_id(),
+            role: accessibility::Role::Switch,
+            bounds: layout.bounds(),
+            name: Some(self.label.clone()),
+            checked: Some(self.is_active),
+            ..accessibility::Node::default()
+        })
+    }
+
+    fn is_animating(&self) -> bool {
+        self.state.is_animating(self.is_active, self.animation_duration)
+    }
+
+    fn operate(&mut self, operation: &mut dyn widget::Operation<Message>) {
+        let id = self.accessibility_id();
+
+        operation.focusable(self.state, Some(&id));
+    }
+}
+
+impl<'a, Message, Renderer> Toggler<'a, Message, Renderer>
+where
+    Renderer: self::Renderer + text::Renderer,
+{
+    /// Produces the stable [`accessibility::NodeId`] screen readers use to
+    /// refer to this [`Toggler`] across frames.
+    ///
+    /// The id is sourced from this [`Toggler`]'s [`State`], which the
+    /// caller owns for the lifetime of the widget, rather than from the
+    /// label: two togglers sharing a label (e.g. two "Enabled" switches
+    /// in a settings list) still need distinct, non-colliding ids.
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    /// [`State`]: struct.State.html
+    /// [`accessibility::NodeId`]: ../../accessibility/struct.NodeId.html
+    fn accessibility_id(&self) -> accessibility::NodeId {
+        self.state.accessibility_id()
+    }
+
+    /// Returns the width the [`Toggler`] should report and lay itself out
+    /// with: an icon-only toggler ([`LabelPosition::None`]) has nothing
+    /// to fill the extra space with, so it always shrinks to the toggle
+    /// box regardless of the configured [`width`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    /// [`LabelPosition::None`]: enum.LabelPosition.html#variant.None
+    /// [`width`]: #method.width
+    fn effective_width(&self) -> Length {
+        if self.label_position == LabelPosition::None {
+            Length::Shrink
+        } else {
+            self.width
+        }
+    }
+
+    fn label_widget(&self, renderer: &Renderer) -> Text<Renderer> {
+        Text::new(&self.label)
+            .horizontal_alignment(
+                self.text_align.unwrap_or(HorizontalAlignment::Left),
+            )
+            .font(self.font)
+            .width(self.width)
+            .size(self.text_size.unwrap_or(renderer.default_size()))
+    }
+}
+
+/// The position of the label of a [`Toggler`], relative to its toggle box.
+///
+/// [`Toggler`]: struct.Toggler.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LabelPosition {
+    /// The label is positioned to the left of the toggle box.
+    Left,
+    /// The label is positioned to the right of the toggle box.
+    Right,
+    /// The [`Toggler`] has no label and sizes to just its toggle box.
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    None,
+}
+
+/// The local state of a [`Toggler`].
+///
+/// It keeps track of things like keyboard focus and the progress of the
+/// thumb's slide animation, so that the widget can react to
+/// [`Space`]/[`Enter`] without a mouse and animate smoothly across frames.
+///
+/// [`Toggler`]: struct.Toggler.html
+/// [`Space`]: ../../keyboard/enum.KeyCode.html#variant.Space
+/// [`Enter`]: ../../keyboard/enum.KeyCode.html#variant.Enter
+#[derive(Debug, Clone)]
+pub struct State {
+    is_focused: bool,
+    is_hovered: bool,
+    transition: Cell<Transition>,
+    accessibility_id: u64,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        use std::sync::atomic::{AtomicU64, Ordering};
+
+        static NEXT_ACCESSIBILITY_ID: AtomicU64 = AtomicU64::new(0);
+
+        State {
+            is_focused: false,
+            is_hovered: false,
+            transition: Cell::new(Transition::default()),
+            accessibility_id: NEXT_ACCESSIBILITY_ID
+                .fetch_add(1, Ordering::Relaxed),
+        }
+    }
+}
+
+/// The thumb's slide animation currently tracked by a [`Toggler`]'s
+/// [`State`].
+///
+/// [`Toggler`]: struct.Toggler.html
+/// [`State`]: struct.State.html
+#[derive(Debug, Clone, Copy)]
+struct Transition {
+    is_active: bool,
+    from: f32,
+    started: Option<Instant>,
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Transition {
+            is_active: false,
+            from: 0.0,
+            started: None,
+        }
+    }
+}
+
+impl Transition {
+    fn target(&self) -> f32 {
+        if self.is_active {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    fn progress(&self, duration: Duration) -> f32 {
+        match self.started {
+            None => self.target(),
+            Some(started) => {
+                if duration.as_millis() == 0 {
+                    return self.target();
+                }
+
+                let elapsed = started.elapsed();
+
+                if elapsed >= duration {
+                    self.target()
+                } else {
+                    let t =
+                        elapsed.as_secs_f32() / duration.as_secs_f32();
+                    let eased = 1.0 - (1.0 - t).powi(3);
+
+                    self.from + (self.target() - self.from) * eased
+                }
+            }
+        }
+    }
+
+    fn is_animating(&self, duration: Duration) -> bool {
+        match self.started {
+            None => false,
+            Some(started) => {
+                duration.as_millis() > 0 && started.elapsed() < duration
+            }
+        }
+    }
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the [`Toggler`] is currently focused or not.
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Focuses the [`Toggler`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    /// Unfocuses the [`Toggler`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+
+    /// Returns whether the mouse cursor is currently over the [`Toggler`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn is_hovered(&self) -> bool {
+        self.is_hovered
+    }
+
+    /// Sets whether the mouse cursor is currently over the [`Toggler`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn set_hovered(&mut self, is_hovered: bool) {
+        self.is_hovered = is_hovered;
+    }
+
+    /// Returns the [`accessibility::NodeId`] uniquely identifying the
+    /// [`Toggler`] this [`State`] belongs to, stable for as long as this
+    /// [`State`] lives.
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    /// [`State`]: struct.State.html
+    /// [`accessibility::NodeId`]: ../../accessibility/struct.NodeId.html
+    fn accessibility_id(&self) -> accessibility::NodeId {
+        accessibility::NodeId::new(self.accessibility_id)
+    }
+
+    /// Reconciles the tracked animation with the latest `is_active` value
+    /// and returns the eased progress (`0.0` off, `1.0` on) the thumb
+    /// should currently be drawn at.
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    fn progress(&self, is_active: bool, duration: Duration) -> f32 {
+        self.reconcile(is_active, duration);
+
+        self.transition.get().progress(duration)
+    }
+
+    /// Returns `true` while the tracked animation is still in progress.
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    fn is_animating(&self, is_active: bool, duration: Duration) -> bool {
+        self.reconcile(is_active, duration);
+
+        self.transition.get().is_animating(duration)
+    }
+
+    fn reconcile(&self, is_active: bool, duration: Duration) {
+        let transition = self.transition.get();
+
+        if transition.is_active != is_active {
+            self.transition.set(Transition {
+                is_active,
+                from: transition.progress(duration),
+                started: Some(Instant::now()),
+            });
+        }
+    }
+}
+
+impl widget::operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        State::is_focused(self)
+    }
+
+    fn focus(&mut self) {
+        State::focus(self)
+    }
+
+    fn unfocus(&mut self) {
+        State::unfocus(self)
     }
 }
 
@@ -258,7 +692,12 @@ pub trait Renderer: crate::Renderer {
     ///   * the bounds of the [`Toggler`]
     ///   * whether the [`Toggler`] is activated or not
     ///   * whether the mouse is over the [`Toggler`] or not
-    ///   * the drawn label of the [`Toggler`]
+    ///   * whether the [`Toggler`] currently has keyboard focus (gained by
+    ///     clicking it or through a focus operation)
+    ///   * the eased progress (`0.0` off, `1.0` on) of the thumb's slide
+    ///     animation, to be used for positioning it via
+    ///     `lerp(off_x, on_x, progress)`
+    ///   * the drawn label of the [`Toggler`], if any
     ///   * the style of the [`Toggler`]
     ///
     /// [`Toggler`]: struct.Toggler.html
@@ -267,20 +706,136 @@ pub trait Renderer: crate::Renderer {
         bounds: Rectangle,
         is_active: bool,
         is_mouse_over: bool,
-        label: Self::Output,
+        is_focused: bool,
+        progress: f32,
+        label: Option<Self::Output>,
         style: &Self::Style,
     ) -> Self::Output;
 }
 
-impl<'a, Message, Renderer> From<Toggler<Message, Renderer>>
+impl<'a, Message, Renderer> From<Toggler<'a, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
     Renderer: 'a + self::Renderer + text::Renderer + row::Renderer,
     Message: 'a,
 {
     fn from(
-        toggler: Toggler<Message, Renderer>,
+        toggler: Toggler<'a, Message, Renderer>,
     ) -> Element<'a, Message, Renderer> {
         Element::new(toggler)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn settled_progress_matches_target() {
+        let off = Transition::default();
+        assert_eq!(off.progress(Duration::from_millis(150)), 0.0);
+
+        let on = Transition {
+            is_active: true,
+            from: 0.0,
+            started: None,
+        };
+        assert_eq!(on.progress(Duration::from_millis(150)), 1.0);
+    }
+
+    #[test]
+    fn zero_duration_snaps_to_target_immediately() {
+        let transition = Transition {
+            is_active: true,
+            from: 0.0,
+            started: Some(Instant::now()),
+        };
+
+        assert_eq!(transition.progress(Duration::from_millis(0)), 1.0);
+        assert!(!transition.is_animating(Duration::from_millis(0)));
+    }
+
+    #[test]
+    fn animating_until_duration_elapses() {
+        let transition = Transition {
+            is_active: true,
+            from: 0.0,
+            started: Some(Instant::now()),
+        };
+
+        assert!(transition.is_animating(Duration::from_millis(150)));
+
+        let settled = Transition {
+            is_active: true,
+            from: 0.0,
+            started: Some(Instant::now() - Duration::from_millis(200)),
+        };
+
+        assert!(!settled.is_animating(Duration::from_millis(150)));
+        assert_eq!(settled.progress(Duration::from_millis(150)), 1.0);
+    }
+
+    #[test]
+    fn progress_stays_within_bounds_mid_transition() {
+        let transition = Transition {
+            is_active: true,
+            from: 0.0,
+            started: Some(Instant::now() - Duration::from_millis(75)),
+        };
+
+        let progress = transition.progress(Duration::from_millis(150));
+
+        assert!(progress > 0.0 && progress < 1.0);
+    }
+
+    fn layout_for(position: LabelPosition) -> layout::Node {
+        use crate::Size;
+
+        let mut state = State::new();
+        let toggler: Toggler<'_, (), crate::renderer::Null> =
+            Toggler::new(&mut state, false, "Label", |_| ())
+                .label_position(position);
+
+        let renderer = crate::renderer::Null::default();
+        let limits = layout::Limits::new(Size::ZERO, Size::INFINITY);
+
+        toggler.layout(&renderer, &limits)
+    }
+
+    fn child_bounds(node: &layout::Node) -> Vec<Rectangle> {
+        Layout::new(node).children().map(|child| child.bounds()).collect()
+    }
+
+    fn toggle_box_width() -> f32 {
+        2.0 * f32::from(<crate::renderer::Null as Renderer>::DEFAULT_SIZE)
+    }
+
+    #[test]
+    fn left_label_is_laid_out_before_the_toggle_box() {
+        let node = layout_for(LabelPosition::Left);
+        let children = child_bounds(&node);
+
+        assert_eq!(children.len(), 2);
+        assert!(children[0].x < children[1].x);
+        assert_eq!(children[1].width, toggle_box_width());
+    }
+
+    #[test]
+    fn right_label_is_laid_out_after_the_toggle_box() {
+        let node = layout_for(LabelPosition::Right);
+        let children = child_bounds(&node);
+
+        assert_eq!(children.len(), 2);
+        assert!(children[0].x < children[1].x);
+        assert_eq!(children[0].width, toggle_box_width());
+    }
+
+    #[test]
+    fn no_label_shrinks_to_just_the_toggle_box() {
+        let node = layout_for(LabelPosition::None);
+        let children = child_bounds(&node);
+
+        assert_eq!(children.len(), 1);
+        assert_eq!(node.size().width, toggle_box_width());
+    }
+}